@@ -16,6 +16,7 @@ pub struct DhiCore {
     batch_size: i32,
     custom_types: HashMap<String, HashMap<String, FieldValidator>>,
     debug: bool,
+    coerce: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +25,350 @@ struct FieldValidator {
     required: bool,
 }
 
+/// The kind of mismatch a detailed validation error represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorKind {
+    MissingField,
+    TypeMismatch,
+    NotInEnum,
+    RecordValueMismatch,
+}
+
+impl ErrorKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::MissingField => "MissingField",
+            ErrorKind::TypeMismatch => "TypeMismatch",
+            ErrorKind::NotInEnum => "NotInEnum",
+            ErrorKind::RecordValueMismatch => "RecordValueMismatch",
+        }
+    }
+}
+
+/// A single structured validation failure, carrying the dotted path to the
+/// offending value so callers don't just get a pass/fail boolean.
+#[derive(Debug, Clone)]
+struct ValidationError {
+    path: String,
+    kind: ErrorKind,
+    expected: String,
+    found: String,
+}
+
+impl ValidationError {
+    fn to_js(&self) -> JsValue {
+        let obj = Object::new();
+        let _ = Reflect::set(&obj, &JsValue::from_str("path"), &JsValue::from_str(&self.path));
+        let _ = Reflect::set(&obj, &JsValue::from_str("kind"), &JsValue::from_str(self.kind.as_str()));
+        let _ = Reflect::set(&obj, &JsValue::from_str("expected"), &JsValue::from_str(&self.expected));
+        let _ = Reflect::set(&obj, &JsValue::from_str("found"), &JsValue::from_str(&self.found));
+        obj.into()
+    }
+}
+
+fn errors_to_js_array(errors: &[ValidationError]) -> Array {
+    let array = Array::new();
+    for error in errors {
+        array.push(&error.to_js());
+    }
+    array
+}
+
+fn join_path(path: &[String]) -> String {
+    path.join(".")
+}
+
+fn field_type_name(field_type: &FieldType) -> String {
+    match field_type {
+        FieldType::String => "string".to_string(),
+        FieldType::Number => "number".to_string(),
+        FieldType::Boolean => "boolean".to_string(),
+        FieldType::Array(inner) => format!("Array<{}>", field_type_name(inner)),
+        FieldType::Object(_) => "object".to_string(),
+        FieldType::Custom(name) => name.clone(),
+        FieldType::Any => "any".to_string(),
+        FieldType::Record(inner) => format!("Record<{}>", field_type_name(inner)),
+        FieldType::Date => "date".to_string(),
+        FieldType::BigInt => "bigint".to_string(),
+        FieldType::Symbol => "symbol".to_string(),
+        FieldType::Undefined => "undefined".to_string(),
+        FieldType::Null => "null".to_string(),
+        FieldType::Void => "void".to_string(),
+        FieldType::Unknown => "unknown".to_string(),
+        FieldType::Never => "never".to_string(),
+        FieldType::Enum(values) => format!("enum:{}", values.join(",")),
+        FieldType::Union(arms) => format!(
+            "Union<{}>",
+            arms.iter().map(field_type_name).collect::<Vec<_>>().join("|")
+        ),
+        FieldType::TaggedUnion { tag, variants } => format!(
+            "Union({}: {})",
+            tag,
+            variants.keys().cloned().collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+/// One token of a compiled path-selector expression (see [`compile_path`]).
+#[derive(Debug, Clone)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+    Descend,
+}
+
+/// Compile a dotted path string (`user.address.city`, `items.0`,
+/// `items.*`, `**.id`) into a small token vector that [`select_segments`]
+/// can walk against a `JsValue` tree.
+fn compile_path(path: &str) -> Vec<Segment> {
+    path.split('.')
+        .map(|part| {
+            if part == "*" {
+                Segment::Wildcard
+            } else if part == "**" {
+                Segment::Descend
+            } else if let Ok(index) = part.parse::<usize>() {
+                Segment::Index(index)
+            } else {
+                Segment::Key(part.to_string())
+            }
+        })
+        .collect()
+}
+
+/// Walk `value` following `segments`, returning every matched node.
+fn select_segments(value: &JsValue, segments: &[Segment]) -> Vec<JsValue> {
+    let (segment, rest) = match segments.split_first() {
+        Some(parts) => parts,
+        None => return vec![value.clone()],
+    };
+
+    match segment {
+        Segment::Key(key) => {
+            if let Some(obj) = value.dyn_ref::<Object>() {
+                if Reflect::has(obj, &JsValue::from_str(key)).unwrap_or(false) {
+                    let next = Reflect::get(obj, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED);
+                    return select_segments(&next, rest);
+                }
+            }
+            Vec::new()
+        }
+        Segment::Index(index) => {
+            if let Some(array) = value.dyn_ref::<Array>() {
+                if (*index as u32) < array.length() {
+                    return select_segments(&array.get(*index as u32), rest);
+                }
+            }
+            Vec::new()
+        }
+        Segment::Wildcard => {
+            let mut results = Vec::new();
+            if let Some(array) = value.dyn_ref::<Array>() {
+                for i in 0..array.length() {
+                    results.extend(select_segments(&array.get(i), rest));
+                }
+            } else if let Some(obj) = value.dyn_ref::<Object>() {
+                let values = Object::values(obj);
+                for i in 0..values.length() {
+                    results.extend(select_segments(&values.get(i), rest));
+                }
+            }
+            results
+        }
+        Segment::Descend => {
+            let mut results = Vec::new();
+            collect_descendants(value, rest, &mut results);
+            results
+        }
+    }
+}
+
+/// Match `rest` against `value` itself, then recurse into every child and
+/// try again — this is what makes `**` a recursive-descent operator rather
+/// than a single-level wildcard.
+fn collect_descendants(value: &JsValue, rest: &[Segment], results: &mut Vec<JsValue>) {
+    results.extend(select_segments(value, rest));
+    if let Some(array) = value.dyn_ref::<Array>() {
+        for i in 0..array.length() {
+            collect_descendants(&array.get(i), rest, results);
+        }
+    } else if let Some(obj) = value.dyn_ref::<Object>() {
+        let values = Object::values(obj);
+        for i in 0..values.length() {
+            collect_descendants(&values.get(i), rest, results);
+        }
+    }
+}
+
+/// A single instruction in a schema compiled by [`DhiCore::compile_program`].
+/// `Array`/`Record`/`Union`/`TaggedUnion` hold their own sub-programs so the
+/// executor only ever recurses at a genuine scope boundary, never to re-walk
+/// a `HashMap` the way `validate_value_internal` does per row.
+#[derive(Debug, Clone)]
+enum Op {
+    /// Fetch `name` off the current object-context. `skip` is the number of
+    /// ops immediately following (this field's compiled check) to jump over
+    /// when the field is absent and optional.
+    EnterField { name: JsValue, required: bool, skip: usize },
+    ExpectType(FieldType),
+    EnterObject,
+    LeaveObject,
+    EnterArrayElems(Vec<Op>),
+    EnterRecordValues(Vec<Op>),
+    EnterUnion(Vec<Vec<Op>>),
+    EnterTaggedUnion { tag: String, variants: HashMap<String, Vec<Op>> },
+}
+
+/// Leaf-level type check used by [`Op::ExpectType`]; mirrors
+/// [`DhiCore::validate_value`]'s primitive arms but returns a plain `bool`
+/// so the compiled executor never allocates a `JsValue` error per failure.
+fn expect_type(value: &JsValue, field_type: &FieldType) -> bool {
+    match field_type {
+        FieldType::String => value.is_string(),
+        FieldType::Number => value.as_f64().is_some(),
+        FieldType::Boolean => value.as_bool().is_some(),
+        FieldType::Date => value.is_instance_of::<js_sys::Date>(),
+        FieldType::BigInt => value.is_bigint(),
+        FieldType::Symbol => value.is_symbol(),
+        FieldType::Undefined => value.is_undefined(),
+        FieldType::Null => value.is_null(),
+        FieldType::Void => value.is_undefined(),
+        FieldType::Unknown => true,
+        FieldType::Never => false,
+        FieldType::Any => true,
+        FieldType::Enum(allowed_values) => value
+            .as_string()
+            .map(|s| allowed_values.contains(&s))
+            .unwrap_or(false),
+        _ => unreachable!("container types are compiled to dedicated ops, not ExpectType"),
+    }
+}
+
+/// Run a compiled program against `value` using an explicit program-counter
+/// loop plus a context stack for `EnterObject`/`LeaveObject` nesting,
+/// instead of recursing through `validate_value_internal`. The executor only
+/// makes a Rust call at a genuine sub-program boundary (array elements,
+/// record values, union arms, tagged-union variants).
+fn execute_program(ops: &[Op], root: &JsValue) -> bool {
+    let mut context_stack: Vec<JsValue> = vec![root.clone()];
+    let mut current = root.clone();
+    let mut pc = 0usize;
+
+    while pc < ops.len() {
+        match &ops[pc] {
+            Op::EnterField { name, required, skip } => {
+                let context = context_stack.last().expect("context stack is never empty");
+                let obj = match context.dyn_ref::<Object>() {
+                    Some(o) => o,
+                    None => return false,
+                };
+                if !Reflect::has(obj, name).unwrap_or(false) {
+                    if *required {
+                        return false;
+                    }
+                    pc += 1 + skip;
+                    continue;
+                }
+                current = Reflect::get(obj, name).unwrap_or(JsValue::UNDEFINED);
+                pc += 1;
+            }
+            Op::ExpectType(field_type) => {
+                if !expect_type(&current, field_type) {
+                    return false;
+                }
+                pc += 1;
+            }
+            Op::EnterObject => {
+                if !current.is_object() {
+                    return false;
+                }
+                context_stack.push(current.clone());
+                pc += 1;
+            }
+            Op::LeaveObject => {
+                context_stack.pop();
+                pc += 1;
+            }
+            Op::EnterArrayElems(item_ops) => {
+                let array = match current.dyn_ref::<Array>() {
+                    Some(a) => a,
+                    None => return false,
+                };
+                for i in 0..array.length() {
+                    if !execute_program(item_ops, &array.get(i)) {
+                        return false;
+                    }
+                }
+                pc += 1;
+            }
+            Op::EnterRecordValues(value_ops) => {
+                let obj = match current.dyn_ref::<Object>() {
+                    Some(o) => o,
+                    None => return false,
+                };
+                let values = Object::values(obj);
+                for i in 0..values.length() {
+                    if !execute_program(value_ops, &values.get(i)) {
+                        return false;
+                    }
+                }
+                pc += 1;
+            }
+            Op::EnterUnion(arm_programs) => {
+                let snapshot = current.clone();
+                if !arm_programs.iter().any(|arm| execute_program(arm, &snapshot)) {
+                    return false;
+                }
+                pc += 1;
+            }
+            Op::EnterTaggedUnion { tag, variants } => {
+                let obj = match current.dyn_ref::<Object>() {
+                    Some(o) => o,
+                    None => return false,
+                };
+                let tag_value = Reflect::get(obj, &JsValue::from_str(tag)).unwrap_or(JsValue::UNDEFINED);
+                let matched = match tag_value.as_string() {
+                    Some(tag_str) => variants.get(&tag_str).map(|program| execute_program(program, &current)),
+                    None => None,
+                };
+                if matched != Some(true) {
+                    return false;
+                }
+                pc += 1;
+            }
+        }
+    }
+
+    true
+}
+
+fn found_type_name(value: &JsValue) -> String {
+    if value.is_null() {
+        "null".to_string()
+    } else if value.is_undefined() {
+        "undefined".to_string()
+    } else if value.is_string() {
+        "string".to_string()
+    } else if value.as_f64().is_some() {
+        "number".to_string()
+    } else if value.as_bool().is_some() {
+        "boolean".to_string()
+    } else if value.is_bigint() {
+        "bigint".to_string()
+    } else if value.is_symbol() {
+        "symbol".to_string()
+    } else if Array::is_array(value) {
+        "array".to_string()
+    } else if value.is_instance_of::<js_sys::Date>() {
+        "date".to_string()
+    } else if value.is_object() {
+        "object".to_string()
+    } else {
+        "unknown".to_string()
+    }
+}
+
 #[derive(Debug, Clone)]
 enum FieldType {
     String,
@@ -43,6 +388,11 @@ enum FieldType {
     Unknown,     // Add Unknown
     Never,       // Add Never
     Enum(Vec<String>),  // Add Enum type
+    Union(Vec<FieldType>),
+    TaggedUnion {
+        tag: String,
+        variants: HashMap<String, HashMap<String, FieldValidator>>,
+    },
 }
 
 #[wasm_bindgen]
@@ -55,6 +405,7 @@ impl DhiCore {
             batch_size: 1000,
             custom_types: HashMap::new(),
             debug: false,
+            coerce: false,
         }
     }
 
@@ -157,70 +508,29 @@ impl DhiCore {
     }
 
     #[wasm_bindgen]
-    pub fn validate_batch(&self, items: Array) -> Result<Array, JsValue> {
+    pub fn validate_batch(&mut self, items: Array) -> Result<Array, JsValue> {
         let results = Array::new();
         let len = items.length() as usize;
         results.set_length(len as u32);
-        
-        // Check if we have any complex types (objects/arrays)
-        let has_complex_types = self.schema.values().any(|v| {
-            matches!(v.field_type, FieldType::Object(_) | FieldType::Array(_) | FieldType::Custom(_))
-        });
 
-        if !has_complex_types {
-            // FAST PATH for simple objects
-            let field_jsvalues: Vec<_> = self.schema.iter()
-                .map(|(k, v)| (JsValue::from_str(k), &v.field_type))
-                .collect();
+        // Lower the schema to a flat instruction program once, up front,
+        // instead of re-walking the schema HashMap and reallocating field
+        // JsValues on every field of every row.
+        let program = self.compile_program();
 
-            for i in 0..len {
+        for chunk_start in (0..len).step_by(CHUNK_SIZE) {
+            let chunk_end = (chunk_start + CHUNK_SIZE).min(len);
+            for i in chunk_start..chunk_end {
                 let item = items.get(i as u32);
-                if !item.is_object() {
-                    results.set(i as u32, JsValue::from_bool(false));
-                    continue;
-                }
-
-                let obj = match item.dyn_ref::<Object>() {
-                    Some(o) => o,
-                    None => {
-                        results.set(i as u32, JsValue::from_bool(false));
-                        continue;
-                    }
-                };
-
-                let mut is_valid = true;
-                for (field_name, field_type) in &field_jsvalues {
-                    match Reflect::has(obj, field_name) {
-                        Ok(true) => {
-                            let value = Reflect::get(obj, field_name).unwrap();
-                            match field_type {
-                                FieldType::String => if !value.is_string() { is_valid = false; break; }
-                                FieldType::Number => if value.as_f64().is_none() { is_valid = false; break; }
-                                FieldType::Boolean => if value.as_bool().is_none() { is_valid = false; break; }
-                                _ => unreachable!()
-                            }
-                        }
-                        _ => {
-                            is_valid = false;
-                            break;
-                        }
-                    }
-                }
+                // A zero-field schema compiles to an empty program, which
+                // `execute_program` would otherwise accept unconditionally;
+                // every root item must still be an object, same as
+                // `validate`/`validate_detailed`.
+                let is_valid = item.is_object() && execute_program(&program, &item);
                 results.set(i as u32, JsValue::from_bool(is_valid));
             }
-        } else {
-            // SLOW PATH for complex objects
-            // ... existing complex validation code ...
-            for chunk_start in (0..len).step_by(CHUNK_SIZE) {
-                let chunk_end = (chunk_start + CHUNK_SIZE).min(len);
-                for i in chunk_start..chunk_end {
-                    let item = items.get(i as u32);
-                    let is_valid = self.validate_value_internal(&item).is_ok();
-                    results.set(i as u32, JsValue::from_bool(is_valid));
-                }
-            }
         }
-        
+
         Ok(results)
     }
 
@@ -229,6 +539,146 @@ impl DhiCore {
         self.debug = debug;
     }
 
+    /// Extract every node of `value` matching the path-selector `path`
+    /// (dotted keys, numeric array indices, `*` wildcards, and a `**`
+    /// recursive-descent operator), without needing a declared schema.
+    #[wasm_bindgen]
+    pub fn select(&self, value: JsValue, path: &str) -> Array {
+        let segments = compile_path(path);
+        let matches = select_segments(&value, &segments);
+        let array = Array::new();
+        for m in matches {
+            array.push(&m);
+        }
+        array
+    }
+
+    /// Validate every node matching `path` against `field_type`, returning
+    /// `true` only if all of them pass (vacuously true if nothing matches).
+    #[wasm_bindgen]
+    pub fn validate_at(&self, value: JsValue, path: &str, field_type: &str) -> Result<bool, JsValue> {
+        let parsed_type = self.parse_field_type(field_type)?;
+        let segments = compile_path(path);
+        let matches = select_segments(&value, &segments);
+        Ok(matches.iter().all(|m| self.validate_value(m, &parsed_type).is_ok()))
+    }
+
+    /// Toggle coercion mode for [`validate_and_coerce`]. When off,
+    /// `validate_and_coerce` behaves like the strict `validate` path.
+    #[wasm_bindgen]
+    pub fn set_coerce(&mut self, coerce: bool) {
+        self.coerce = coerce;
+    }
+
+    /// Validate `value`, and when coercion is enabled, rebuild it with
+    /// conversions applied (numeric strings to numbers, "true"/"false" to
+    /// booleans, ISO-8601 strings/epoch numbers to dates, etc.) instead of
+    /// just checking it. Returns the rebuilt value on success, or the same
+    /// structured error array as [`validate_detailed`] on failure.
+    #[wasm_bindgen]
+    pub fn validate_and_coerce(&self, value: JsValue) -> Result<JsValue, JsValue> {
+        if !self.coerce {
+            let mut path = Vec::new();
+            let mut errors = Vec::new();
+            self.validate_value_internal_detailed(&value, &mut path, &mut errors);
+            return if errors.is_empty() {
+                Ok(value)
+            } else {
+                Err(errors_to_js_array(&errors).into())
+            };
+        }
+
+        let mut path = Vec::new();
+        let mut errors = Vec::new();
+        let obj = match value.dyn_ref::<Object>() {
+            Some(o) => o,
+            None => {
+                // Same root-level TypeMismatch entry `validate_detailed` reports.
+                self.validate_value_internal_detailed(&value, &mut path, &mut errors);
+                return Err(errors_to_js_array(&errors).into());
+            }
+        };
+
+        let result = self.coerce_object(obj, &self.schema, &mut path, &mut errors);
+
+        if errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(errors_to_js_array(&errors).into())
+        }
+    }
+
+    /// Load a declarative, Avro-flavoured schema document in one call instead
+    /// of building it up with repeated `add_field`/`add_nested_field` calls:
+    /// `{"type":"record","name":"User","fields":[{"name":"age","type":"number"}]}`.
+    /// Named nested records are registered in `custom_types` so they can be
+    /// reused across fields by name.
+    #[wasm_bindgen]
+    pub fn load_schema(&mut self, schema: JsValue) -> Result<(), JsValue> {
+        let obj = schema.dyn_ref::<Object>()
+            .ok_or_else(|| JsValue::from_str("Schema must be an object"))?;
+
+        let type_str = Reflect::get(obj, &JsValue::from_str("type"))?
+            .as_string()
+            .ok_or_else(|| JsValue::from_str("Schema missing 'type'"))?;
+        if type_str != "record" {
+            return Err(JsValue::from_str("Root schema must be of type 'record'"));
+        }
+
+        let fields = Reflect::get(obj, &JsValue::from_str("fields"))?;
+        let fields_array = fields.dyn_ref::<Array>()
+            .ok_or_else(|| JsValue::from_str("Record 'fields' must be an array"))?;
+
+        let mut new_schema = HashMap::new();
+        for i in 0..fields_array.length() {
+            let field = fields_array.get(i);
+            let (name, validator) = self.parse_schema_field(&field)?;
+            new_schema.insert(name, validator);
+        }
+        self.schema = new_schema;
+        Ok(())
+    }
+
+    /// Serialize the current schema back into the declarative document format
+    /// accepted by [`load_schema`], so it can be persisted or shipped to
+    /// another process. A tagged union, alone or as a union arm, is written
+    /// as its tag and variant names only, which `load_schema` does not
+    /// accept back.
+    #[wasm_bindgen]
+    pub fn export_schema(&self) -> JsValue {
+        let root = Object::new();
+        let _ = Reflect::set(&root, &JsValue::from_str("type"), &JsValue::from_str("record"));
+        let _ = Reflect::set(&root, &JsValue::from_str("name"), &JsValue::from_str("Root"));
+        let _ = Reflect::set(&root, &JsValue::from_str("fields"), &self.schema_to_fields_array(&self.schema));
+        root.into()
+    }
+
+    /// Validate `value` against the root schema, returning every failure
+    /// found rather than stopping at the first one. Each entry is an object
+    /// `{path, kind, expected, found}`, e.g. `{path: "user.address.zip", kind:
+    /// "TypeMismatch", expected: "string", found: "number"}`.
+    #[wasm_bindgen]
+    pub fn validate_detailed(&self, value: JsValue) -> JsValue {
+        let mut path = Vec::new();
+        let mut errors = Vec::new();
+        self.validate_value_internal_detailed(&value, &mut path, &mut errors);
+        errors_to_js_array(&errors).into()
+    }
+
+    /// Batch form of [`validate_detailed`]: one error array per item.
+    #[wasm_bindgen]
+    pub fn validate_batch_detailed(&self, items: Array) -> Array {
+        let results = Array::new();
+        for i in 0..items.length() {
+            let item = items.get(i);
+            let mut path = Vec::new();
+            let mut errors = Vec::new();
+            self.validate_value_internal_detailed(&item, &mut path, &mut errors);
+            results.push(&errors_to_js_array(&errors));
+        }
+        results
+    }
+
     fn validate_value_internal(&self, value: &JsValue) -> Result<(), JsValue> {
         if !value.is_object() {
             return Err(JsValue::from_bool(false));
@@ -280,6 +730,718 @@ impl DhiCore {
         Ok(())
     }
 
+    /// Path-accumulating counterpart to [`validate_value_internal`]: pushes a
+    /// field segment before descending into it and pops it back off after,
+    /// recording an error entry instead of bailing on the first failure.
+    fn validate_value_internal_detailed(
+        &self,
+        value: &JsValue,
+        path: &mut Vec<String>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let obj = match value.dyn_ref::<Object>() {
+            Some(o) => o,
+            None => {
+                errors.push(ValidationError {
+                    path: join_path(path),
+                    kind: ErrorKind::TypeMismatch,
+                    expected: "object".to_string(),
+                    found: found_type_name(value),
+                });
+                return;
+            }
+        };
+
+        for (field_name, validator) in &self.schema {
+            let has_field = Reflect::has(obj, &JsValue::from_str(field_name)).unwrap_or(false);
+            if !has_field {
+                if validator.required {
+                    path.push(field_name.clone());
+                    errors.push(ValidationError {
+                        path: join_path(path),
+                        kind: ErrorKind::MissingField,
+                        expected: field_type_name(&validator.field_type),
+                        found: "undefined".to_string(),
+                    });
+                    path.pop();
+                }
+                continue;
+            }
+
+            let field_value = Reflect::get(obj, &JsValue::from_str(field_name)).unwrap_or(JsValue::UNDEFINED);
+            path.push(field_name.clone());
+            self.validate_value_detailed(&field_value, &validator.field_type, path, errors);
+            path.pop();
+        }
+    }
+
+    /// Path-accumulating counterpart to [`validate_object`].
+    fn validate_object_detailed(
+        &self,
+        value: &JsValue,
+        schema: &HashMap<String, FieldValidator>,
+        path: &mut Vec<String>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let obj = match value.dyn_ref::<Object>() {
+            Some(o) => o,
+            None => {
+                errors.push(ValidationError {
+                    path: join_path(path),
+                    kind: ErrorKind::TypeMismatch,
+                    expected: "object".to_string(),
+                    found: found_type_name(value),
+                });
+                return;
+            }
+        };
+
+        for (field_name, validator) in schema {
+            let has_field = Reflect::has(obj, &JsValue::from_str(field_name)).unwrap_or(false);
+            if !has_field {
+                if validator.required {
+                    path.push(field_name.clone());
+                    errors.push(ValidationError {
+                        path: join_path(path),
+                        kind: ErrorKind::MissingField,
+                        expected: field_type_name(&validator.field_type),
+                        found: "undefined".to_string(),
+                    });
+                    path.pop();
+                }
+                continue;
+            }
+
+            let field_value = Reflect::get(obj, &JsValue::from_str(field_name)).unwrap_or(JsValue::UNDEFINED);
+            path.push(field_name.clone());
+            self.validate_value_detailed(&field_value, &validator.field_type, path, errors);
+            path.pop();
+        }
+    }
+
+    /// Path-accumulating counterpart to [`validate_value`].
+    fn validate_value_detailed(
+        &self,
+        value: &JsValue,
+        field_type: &FieldType,
+        path: &mut Vec<String>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mismatch = |errors: &mut Vec<ValidationError>, path: &[String], expected: &str| {
+            errors.push(ValidationError {
+                path: join_path(path),
+                kind: ErrorKind::TypeMismatch,
+                expected: expected.to_string(),
+                found: found_type_name(value),
+            });
+        };
+
+        match field_type {
+            FieldType::String => {
+                if !value.is_string() {
+                    mismatch(errors, path, "string");
+                }
+            }
+            FieldType::Number => {
+                if value.as_f64().is_none() {
+                    mismatch(errors, path, "number");
+                }
+            }
+            FieldType::Boolean => {
+                if value.as_bool().is_none() {
+                    mismatch(errors, path, "boolean");
+                }
+            }
+            FieldType::Array(item_type) => {
+                let array = match value.dyn_ref::<Array>() {
+                    Some(a) => a,
+                    None => {
+                        mismatch(errors, path, &field_type_name(field_type));
+                        return;
+                    }
+                };
+                for i in 0..array.length() {
+                    let item = array.get(i);
+                    path.push(i.to_string());
+                    self.validate_value_detailed(&item, item_type, path, errors);
+                    path.pop();
+                }
+            }
+            FieldType::Object(nested_schema) => {
+                self.validate_object_detailed(value, nested_schema, path, errors);
+            }
+            FieldType::Custom(type_name) => {
+                if let Some(custom_type) = self.custom_types.get(type_name) {
+                    self.validate_object_detailed(value, custom_type, path, errors);
+                }
+            }
+            FieldType::Record(value_type) => {
+                let obj = match value.dyn_ref::<Object>() {
+                    Some(o) => o,
+                    None => {
+                        mismatch(errors, path, "record");
+                        return;
+                    }
+                };
+                let entries = Object::entries(obj);
+                for i in 0..entries.length() {
+                    let entry = entries.get(i);
+                    let key = Reflect::get(&entry, &JsValue::from(0)).unwrap_or(JsValue::UNDEFINED);
+                    let entry_value = Reflect::get(&entry, &JsValue::from(1)).unwrap_or(JsValue::UNDEFINED);
+                    path.push(key.as_string().unwrap_or_default());
+                    if self.validate_value(&entry_value, value_type).is_err() {
+                        errors.push(ValidationError {
+                            path: join_path(path),
+                            kind: ErrorKind::RecordValueMismatch,
+                            expected: field_type_name(value_type),
+                            found: found_type_name(&entry_value),
+                        });
+                    }
+                    path.pop();
+                }
+            }
+            FieldType::Date => {
+                if !value.is_instance_of::<js_sys::Date>() {
+                    mismatch(errors, path, "date");
+                }
+            }
+            FieldType::BigInt => {
+                if !value.is_bigint() {
+                    mismatch(errors, path, "bigint");
+                }
+            }
+            FieldType::Symbol => {
+                if !value.is_symbol() {
+                    mismatch(errors, path, "symbol");
+                }
+            }
+            FieldType::Undefined => {
+                if !value.is_undefined() {
+                    mismatch(errors, path, "undefined");
+                }
+            }
+            FieldType::Null => {
+                if !value.is_null() {
+                    mismatch(errors, path, "null");
+                }
+            }
+            FieldType::Void => {
+                if !value.is_undefined() {
+                    mismatch(errors, path, "void");
+                }
+            }
+            FieldType::Unknown => {}
+            FieldType::Never => mismatch(errors, path, "never"),
+            FieldType::Any => {}
+            FieldType::Enum(allowed_values) => {
+                if let Some(str_val) = value.as_string() {
+                    if !allowed_values.contains(&str_val) {
+                        errors.push(ValidationError {
+                            path: join_path(path),
+                            kind: ErrorKind::NotInEnum,
+                            expected: field_type_name(field_type),
+                            found: str_val,
+                        });
+                    }
+                } else {
+                    mismatch(errors, path, &field_type_name(field_type));
+                }
+            }
+            FieldType::Union(arms) => {
+                let matched = arms.iter().any(|arm| self.validate_value(value, arm).is_ok());
+                if !matched {
+                    mismatch(errors, path, &field_type_name(field_type));
+                }
+            }
+            FieldType::TaggedUnion { tag, variants } => {
+                let obj = match value.dyn_ref::<Object>() {
+                    Some(o) => o,
+                    None => {
+                        mismatch(errors, path, "object");
+                        return;
+                    }
+                };
+                let tag_value = Reflect::get(obj, &JsValue::from_str(tag)).unwrap_or(JsValue::UNDEFINED);
+                match tag_value.as_string() {
+                    Some(tag_str) => match variants.get(&tag_str) {
+                        Some(variant_schema) => {
+                            self.validate_object_detailed(value, variant_schema, path, errors);
+                        }
+                        None => {
+                            errors.push(ValidationError {
+                                path: join_path(path),
+                                kind: ErrorKind::NotInEnum,
+                                expected: format!("one of: {}", variants.keys().cloned().collect::<Vec<_>>().join(", ")),
+                                found: tag_str,
+                            });
+                        }
+                    },
+                    None => {
+                        errors.push(ValidationError {
+                            path: join_path(path),
+                            kind: ErrorKind::MissingField,
+                            expected: format!("tag '{}'", tag),
+                            found: "undefined".to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parse one entry of a declarative schema's `fields` array into a
+    /// `(name, FieldValidator)` pair.
+    fn parse_schema_field(&mut self, field: &JsValue) -> Result<(String, FieldValidator), JsValue> {
+        let obj = field.dyn_ref::<Object>()
+            .ok_or_else(|| JsValue::from_str("Field definition must be an object"))?;
+
+        let name = Reflect::get(obj, &JsValue::from_str("name"))?
+            .as_string()
+            .ok_or_else(|| JsValue::from_str("Field definition missing 'name'"))?;
+
+        let type_val = Reflect::get(obj, &JsValue::from_str("type"))?;
+        let explicit_optional = Reflect::get(obj, &JsValue::from_str("optional"))?
+            .as_bool()
+            .unwrap_or(false);
+
+        let (field_type, union_optional) = self.parse_schema_type(&type_val, &name)?;
+
+        Ok((name, FieldValidator {
+            field_type,
+            required: !explicit_optional && !union_optional,
+        }))
+    }
+
+    /// Parse a declarative schema `type` value (primitive string, nested
+    /// record/array/enum object). Returns whether a `"null"` union arm made
+    /// the field implicitly optional.
+    fn parse_schema_type(&mut self, type_val: &JsValue, field_name: &str) -> Result<(FieldType, bool), JsValue> {
+        if let Some(type_name) = type_val.as_string() {
+            if type_name == "null" {
+                return Ok((FieldType::Null, true));
+            }
+            // `export_schema` writes `FieldType::Any` as "any".
+            if type_name == "any" {
+                return Ok((FieldType::Any, false));
+            }
+            return Ok((self.parse_field_type(&type_name)?, false));
+        }
+
+        if let Some(arms) = type_val.dyn_ref::<Array>() {
+            // Avro-style union shorthand: ["null", "string"] means optional string.
+            let mut has_null = false;
+            let mut non_null_types = Vec::new();
+            for i in 0..arms.length() {
+                let arm = arms.get(i);
+                let (arm_type, arm_optional) = self.parse_schema_type(&arm, field_name)?;
+                if arm_optional {
+                    has_null = true;
+                } else {
+                    non_null_types.push(arm_type);
+                }
+            }
+            let field_type = if non_null_types.is_empty() {
+                FieldType::Any
+            } else if non_null_types.len() == 1 {
+                non_null_types.remove(0)
+            } else {
+                FieldType::Union(non_null_types)
+            };
+            return Ok((field_type, has_null));
+        }
+
+        let obj = type_val.dyn_ref::<Object>()
+            .ok_or_else(|| JsValue::from_str("Field 'type' must be a string, array, or object"))?;
+
+        let kind = Reflect::get(obj, &JsValue::from_str("type"))?
+            .as_string()
+            .ok_or_else(|| JsValue::from_str("Complex field type missing 'type'"))?;
+
+        match kind.as_str() {
+            "array" => {
+                let items = Reflect::get(obj, &JsValue::from_str("items"))?;
+                let (item_type, _) = self.parse_schema_type(&items, field_name)?;
+                Ok((FieldType::Array(Box::new(item_type)), false))
+            }
+            "map" => {
+                let values = Reflect::get(obj, &JsValue::from_str("values"))?;
+                let (value_type, _) = self.parse_schema_type(&values, field_name)?;
+                Ok((FieldType::Record(Box::new(value_type)), false))
+            }
+            "enum" => {
+                let symbols = Reflect::get(obj, &JsValue::from_str("symbols"))?;
+                let symbols_array = symbols.dyn_ref::<Array>()
+                    .ok_or_else(|| JsValue::from_str("Enum 'symbols' must be an array"))?;
+                let values = (0..symbols_array.length())
+                    .filter_map(|i| symbols_array.get(i).as_string())
+                    .collect();
+                Ok((FieldType::Enum(values), false))
+            }
+            "record" => {
+                let record_name = Reflect::get(obj, &JsValue::from_str("name"))?
+                    .as_string()
+                    .unwrap_or_else(|| format!("{}Type", field_name));
+
+                let fields = Reflect::get(obj, &JsValue::from_str("fields"))?;
+                let fields_array = fields.dyn_ref::<Array>()
+                    .ok_or_else(|| JsValue::from_str("Record 'fields' must be an array"))?;
+
+                let mut nested_schema = HashMap::new();
+                for i in 0..fields_array.length() {
+                    let field = fields_array.get(i);
+                    let (name, validator) = self.parse_schema_field(&field)?;
+                    nested_schema.insert(name, validator);
+                }
+
+                self.custom_types.insert(record_name.clone(), nested_schema);
+                Ok((FieldType::Custom(record_name), false))
+            }
+            other => Err(JsValue::from_str(&format!("Unsupported complex field type: {}", other))),
+        }
+    }
+
+    /// Serialize `schema` back into a declarative `fields` array, recursing
+    /// into custom types by name so named records round-trip.
+    fn schema_to_fields_array(&self, schema: &HashMap<String, FieldValidator>) -> Array {
+        let fields = Array::new();
+        for (name, validator) in schema {
+            let field_obj = Object::new();
+            let _ = Reflect::set(&field_obj, &JsValue::from_str("name"), &JsValue::from_str(name));
+            let _ = Reflect::set(&field_obj, &JsValue::from_str("type"), &self.field_type_to_schema_value(&validator.field_type));
+            if !validator.required {
+                let _ = Reflect::set(&field_obj, &JsValue::from_str("optional"), &JsValue::from_bool(true));
+            }
+            fields.push(&field_obj);
+        }
+        fields
+    }
+
+    fn field_type_to_schema_value(&self, field_type: &FieldType) -> JsValue {
+        match field_type {
+            FieldType::Array(inner) => {
+                let obj = Object::new();
+                let _ = Reflect::set(&obj, &JsValue::from_str("type"), &JsValue::from_str("array"));
+                let _ = Reflect::set(&obj, &JsValue::from_str("items"), &self.field_type_to_schema_value(inner));
+                obj.into()
+            }
+            FieldType::Record(inner) => {
+                let obj = Object::new();
+                let _ = Reflect::set(&obj, &JsValue::from_str("type"), &JsValue::from_str("map"));
+                let _ = Reflect::set(&obj, &JsValue::from_str("values"), &self.field_type_to_schema_value(inner));
+                obj.into()
+            }
+            FieldType::Enum(values) => {
+                let obj = Object::new();
+                let _ = Reflect::set(&obj, &JsValue::from_str("type"), &JsValue::from_str("enum"));
+                let symbols = Array::new();
+                for v in values {
+                    symbols.push(&JsValue::from_str(v));
+                }
+                let _ = Reflect::set(&obj, &JsValue::from_str("symbols"), &symbols);
+                obj.into()
+            }
+            FieldType::Object(nested) => {
+                let obj = Object::new();
+                let _ = Reflect::set(&obj, &JsValue::from_str("type"), &JsValue::from_str("record"));
+                let _ = Reflect::set(&obj, &JsValue::from_str("fields"), &self.schema_to_fields_array(nested));
+                obj.into()
+            }
+            FieldType::Custom(name) => {
+                let obj = Object::new();
+                let _ = Reflect::set(&obj, &JsValue::from_str("type"), &JsValue::from_str("record"));
+                let _ = Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_str(name));
+                if let Some(custom_type) = self.custom_types.get(name) {
+                    let _ = Reflect::set(&obj, &JsValue::from_str("fields"), &self.schema_to_fields_array(custom_type));
+                }
+                obj.into()
+            }
+            _ => JsValue::from_str(&field_type_name(field_type)),
+        }
+    }
+
+    /// Coercing counterpart to [`validate_object_detailed`]: rebuilds an
+    /// object field-by-field instead of only checking it, recording a
+    /// structured error for any leaf that cannot be coerced.
+    fn coerce_object(
+        &self,
+        obj: &Object,
+        schema: &HashMap<String, FieldValidator>,
+        path: &mut Vec<String>,
+        errors: &mut Vec<ValidationError>,
+    ) -> JsValue {
+        let result = Object::new();
+        for (field_name, validator) in schema {
+            let has_field = Reflect::has(obj, &JsValue::from_str(field_name)).unwrap_or(false);
+            if !has_field {
+                if validator.required {
+                    path.push(field_name.clone());
+                    errors.push(ValidationError {
+                        path: join_path(path),
+                        kind: ErrorKind::MissingField,
+                        expected: field_type_name(&validator.field_type),
+                        found: "undefined".to_string(),
+                    });
+                    path.pop();
+                }
+                continue;
+            }
+
+            let field_value = Reflect::get(obj, &JsValue::from_str(field_name)).unwrap_or(JsValue::UNDEFINED);
+            path.push(field_name.clone());
+            let coerced = self.coerce_value(&field_value, &validator.field_type, path, errors);
+            path.pop();
+            let _ = Reflect::set(&result, &JsValue::from_str(field_name), &coerced);
+        }
+        result.into()
+    }
+
+    /// Coercing counterpart to [`validate_value_detailed`]: returns the
+    /// rebuilt leaf value, or the original value plus a pushed error when it
+    /// cannot be converted to `field_type`.
+    fn coerce_value(
+        &self,
+        value: &JsValue,
+        field_type: &FieldType,
+        path: &mut Vec<String>,
+        errors: &mut Vec<ValidationError>,
+    ) -> JsValue {
+        let fail = |errors: &mut Vec<ValidationError>, path: &[String], expected: &str| {
+            errors.push(ValidationError {
+                path: join_path(path),
+                kind: ErrorKind::TypeMismatch,
+                expected: expected.to_string(),
+                found: found_type_name(value),
+            });
+        };
+
+        match field_type {
+            FieldType::String => {
+                if value.is_string() {
+                    return value.clone();
+                }
+                if let Some(n) = value.as_f64() {
+                    return JsValue::from_str(&n.to_string());
+                }
+                if let Some(b) = value.as_bool() {
+                    return JsValue::from_str(&b.to_string());
+                }
+                if value.is_null() {
+                    return JsValue::from_str("null");
+                }
+                // Arrays and objects stringify through JSON (`[1,2]`,
+                // `{"a":1}`), not through JS's own `String(value)` (`1,2`,
+                // `[object Object]`).
+                if value.dyn_ref::<Array>().is_some() || value.dyn_ref::<Object>().is_some() {
+                    if let Ok(json) = js_sys::JSON::stringify(value) {
+                        if let Some(s) = json.as_string() {
+                            return JsValue::from_str(&s);
+                        }
+                    }
+                }
+                fail(errors, path, "string");
+                value.clone()
+            }
+            FieldType::Number => {
+                if value.as_f64().is_some() {
+                    return value.clone();
+                }
+                if let Some(s) = value.as_string() {
+                    if let Ok(n) = s.trim().parse::<f64>() {
+                        return JsValue::from_f64(n);
+                    }
+                }
+                fail(errors, path, "number");
+                value.clone()
+            }
+            FieldType::Boolean => {
+                if value.as_bool().is_some() {
+                    return value.clone();
+                }
+                if let Some(s) = value.as_string() {
+                    match s.as_str() {
+                        "true" => return JsValue::from_bool(true),
+                        "false" => return JsValue::from_bool(false),
+                        _ => {}
+                    }
+                }
+                fail(errors, path, "boolean");
+                value.clone()
+            }
+            FieldType::Date => {
+                if value.is_instance_of::<js_sys::Date>() {
+                    return value.clone();
+                }
+                let date = value
+                    .as_string()
+                    .map(|s| js_sys::Date::new(&JsValue::from_str(&s)))
+                    .or_else(|| value.as_f64().map(|n| js_sys::Date::new(&JsValue::from_f64(n))));
+                match date {
+                    Some(d) if !d.get_time().is_nan() => d.into(),
+                    _ => {
+                        fail(errors, path, "date");
+                        value.clone()
+                    }
+                }
+            }
+            FieldType::BigInt => {
+                if value.is_bigint() {
+                    return value.clone();
+                }
+                if let Some(s) = value.as_string() {
+                    if s.trim().chars().all(|c| c.is_ascii_digit()) && !s.trim().is_empty() {
+                        if let Ok(big) = js_sys::BigInt::new(&JsValue::from_str(s.trim())) {
+                            return big.into();
+                        }
+                    }
+                }
+                fail(errors, path, "bigint");
+                value.clone()
+            }
+            FieldType::Array(item_type) => {
+                let array = match value.dyn_ref::<Array>() {
+                    Some(a) => a,
+                    None => {
+                        fail(errors, path, &field_type_name(field_type));
+                        return value.clone();
+                    }
+                };
+                let result = Array::new();
+                for i in 0..array.length() {
+                    let item = array.get(i);
+                    path.push(i.to_string());
+                    let coerced = self.coerce_value(&item, item_type, path, errors);
+                    path.pop();
+                    result.push(&coerced);
+                }
+                result.into()
+            }
+            FieldType::Object(nested_schema) => {
+                match value.dyn_ref::<Object>() {
+                    Some(o) => self.coerce_object(o, nested_schema, path, errors),
+                    None => {
+                        fail(errors, path, "object");
+                        value.clone()
+                    }
+                }
+            }
+            FieldType::Custom(type_name) => {
+                let nested_schema = match self.custom_types.get(type_name) {
+                    Some(s) => s,
+                    None => return value.clone(),
+                };
+                match value.dyn_ref::<Object>() {
+                    Some(o) => self.coerce_object(o, nested_schema, path, errors),
+                    None => {
+                        fail(errors, path, type_name);
+                        value.clone()
+                    }
+                }
+            }
+            FieldType::Record(value_type) => {
+                let obj = match value.dyn_ref::<Object>() {
+                    Some(o) => o,
+                    None => {
+                        fail(errors, path, "record");
+                        return value.clone();
+                    }
+                };
+                let result = Object::new();
+                let entries = Object::entries(obj);
+                for i in 0..entries.length() {
+                    let entry = entries.get(i);
+                    let key = Reflect::get(&entry, &JsValue::from(0)).unwrap_or(JsValue::UNDEFINED);
+                    let entry_value = Reflect::get(&entry, &JsValue::from(1)).unwrap_or(JsValue::UNDEFINED);
+                    let key_str = key.as_string().unwrap_or_default();
+                    path.push(key_str.clone());
+                    let coerced = self.coerce_value(&entry_value, value_type, path, errors);
+                    path.pop();
+                    let _ = Reflect::set(&result, &JsValue::from_str(&key_str), &coerced);
+                }
+                result.into()
+            }
+            // No coercion defined for these; fall back to a strict check.
+            _ => {
+                if self.validate_value(value, field_type).is_err() {
+                    fail(errors, path, &field_type_name(field_type));
+                }
+                value.clone()
+            }
+        }
+    }
+
+    /// Lower the root schema into a flat [`Op`] program, interning each
+    /// field name into a `JsValue` exactly once regardless of batch size.
+    /// Reuses `self.field_cache` across calls so repeated `validate_batch`
+    /// calls on the same schema don't re-intern field names they've already
+    /// seen.
+    fn compile_program(&mut self) -> Vec<Op> {
+        let mut field_cache = std::mem::take(&mut self.field_cache);
+        let program = self.compile_schema_ops(&self.schema, &mut field_cache);
+        self.field_cache = field_cache;
+        program
+    }
+
+    fn compile_schema_ops(
+        &self,
+        schema: &HashMap<String, FieldValidator>,
+        field_cache: &mut HashMap<String, JsValue>,
+    ) -> Vec<Op> {
+        let mut program = Vec::with_capacity(schema.len() * 2);
+        for (name, validator) in schema {
+            let cached_name = field_cache
+                .entry(name.clone())
+                .or_insert_with(|| JsValue::from_str(name))
+                .clone();
+            let field_ops = self.compile_field_ops(&validator.field_type, field_cache);
+            program.push(Op::EnterField {
+                name: cached_name,
+                required: validator.required,
+                skip: field_ops.len(),
+            });
+            program.extend(field_ops);
+        }
+        program
+    }
+
+    fn compile_field_ops(&self, field_type: &FieldType, field_cache: &mut HashMap<String, JsValue>) -> Vec<Op> {
+        match field_type {
+            FieldType::Object(nested_schema) => {
+                let mut ops = vec![Op::EnterObject];
+                ops.extend(self.compile_schema_ops(nested_schema, field_cache));
+                ops.push(Op::LeaveObject);
+                ops
+            }
+            FieldType::Custom(type_name) => {
+                let mut ops = vec![Op::EnterObject];
+                if let Some(custom_type) = self.custom_types.get(type_name) {
+                    ops.extend(self.compile_schema_ops(custom_type, field_cache));
+                }
+                ops.push(Op::LeaveObject);
+                ops
+            }
+            FieldType::Array(item_type) => {
+                vec![Op::EnterArrayElems(self.compile_field_ops(item_type, field_cache))]
+            }
+            FieldType::Record(value_type) => {
+                vec![Op::EnterRecordValues(self.compile_field_ops(value_type, field_cache))]
+            }
+            FieldType::Union(arms) => {
+                let arm_programs = arms.iter().map(|arm| self.compile_field_ops(arm, field_cache)).collect();
+                vec![Op::EnterUnion(arm_programs)]
+            }
+            FieldType::TaggedUnion { tag, variants } => {
+                let compiled_variants = variants
+                    .iter()
+                    .map(|(variant_name, variant_schema)| {
+                        (variant_name.clone(), self.compile_schema_ops(variant_schema, field_cache))
+                    })
+                    .collect();
+                vec![Op::EnterTaggedUnion { tag: tag.clone(), variants: compiled_variants }]
+            }
+            leaf => vec![Op::ExpectType(leaf.clone())],
+        }
+    }
+
     fn parse_field_type(&self, field_type: &str) -> Result<FieldType, JsValue> {
         match field_type {
             "string" => Ok(FieldType::String),
@@ -305,6 +1467,31 @@ impl DhiCore {
                     let inner = self.parse_field_type(inner_type)?;
                     return Ok(FieldType::Array(Box::new(inner)));
                 }
+                if let Some(inner) = field_type.strip_prefix("Union<").and_then(|s| s.strip_suffix(">")) {
+                    let arms = inner
+                        .split('|')
+                        .map(|arm| self.parse_field_type(arm.trim()))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    return Ok(FieldType::Union(arms));
+                }
+                if let Some(inner) = field_type.strip_prefix("Union(").and_then(|s| s.strip_suffix(")")) {
+                    let (tag_part, variants_part) = inner.split_once(':').ok_or_else(|| {
+                        JsValue::from_str("Tagged union must be of the form 'Union(tag: variant=Type, ...)'")
+                    })?;
+                    let tag = tag_part.trim().to_string();
+                    let mut variants = HashMap::new();
+                    for entry in variants_part.split(',') {
+                        let (variant_name, type_name) = entry.trim().split_once('=').ok_or_else(|| {
+                            JsValue::from_str("Tagged union variant must be of the form 'name=Type'")
+                        })?;
+                        let type_name = type_name.trim();
+                        let variant_fields = self.custom_types.get(type_name).ok_or_else(|| {
+                            JsValue::from_str(&format!("Unknown variant type: {}", type_name))
+                        })?;
+                        variants.insert(variant_name.trim().to_string(), variant_fields.clone());
+                    }
+                    return Ok(FieldType::TaggedUnion { tag, variants });
+                }
                 if let Some(inner_type) = field_type.strip_prefix("Record<").and_then(|s| s.strip_suffix(">")) {
                     let inner = self.parse_field_type(inner_type)?;
                     return Ok(FieldType::Record(Box::new(inner)));
@@ -422,6 +1609,24 @@ impl DhiCore {
                     Err(JsValue::from_bool(false))
                 }
             }
+            FieldType::Union(arms) => {
+                for arm in arms {
+                    if self.validate_value(value, arm).is_ok() {
+                        return Ok(());
+                    }
+                }
+                Err(JsValue::from_bool(false))
+            }
+            FieldType::TaggedUnion { tag, variants } => {
+                let obj = value.dyn_ref::<Object>()
+                    .ok_or_else(|| JsValue::from_bool(false))?;
+                let tag_value = Reflect::get(obj, &JsValue::from_str(tag))?;
+                let tag_str = tag_value.as_string()
+                    .ok_or_else(|| JsValue::from_bool(false))?;
+                let variant_schema = variants.get(&tag_str)
+                    .ok_or_else(|| JsValue::from_bool(false))?;
+                self.validate_object(value, variant_schema)
+            }
         }
     }
 
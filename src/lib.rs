@@ -1,11 +1,121 @@
 use pyo3::prelude::*;
+use regex::Regex;
 use std::collections::HashMap;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::OnceLock;
 
 #[pyclass(name = "StreamValidatorCore")]
 struct StreamValidatorCore {
     schema: HashMap<String, FieldValidator>,
     batch_size: usize,
     custom_types: HashMap<String, HashMap<String, FieldValidator>>,
+    // Cross-field rules evaluated once per item, after the per-field
+    // checks, since they compare values across fields rather than judging
+    // a single field in isolation. `validate_batch` reaches them only once
+    // every field has passed; `validate_batch_detailed` runs them even
+    // after a field has failed.
+    item_rules: Vec<ItemRule>,
+}
+
+/// A record-level invariant checked across an item's fields rather than on
+/// one field in isolation, e.g. "`password` must equal `password_confirm`"
+/// or "`end_date` must be on/after `start_date`".
+enum ItemRule {
+    /// Two fields must hold equal values.
+    MustMatch { field_a: String, field_b: String },
+    /// `field_a` must stand in `op` relation to `field_b` (both numeric, or
+    /// both strings — e.g. ISO 8601 date-times, which sort lexicographically).
+    Compare { field_a: String, field_b: String, op: FieldCompareOp },
+    /// `field` becomes required when `depends_on` is present (and, if
+    /// `equals` is given, equal to that value).
+    RequiredIf { field: String, depends_on: String, equals: Option<Py<PyAny>> },
+    /// `field` becomes required unless `depends_on` is present (and, if
+    /// `equals` is given, equal to that value).
+    RequiredUnless { field: String, depends_on: String, equals: Option<Py<PyAny>> },
+}
+
+impl ItemRule {
+    /// The field whose error path a failure of this rule should be reported
+    /// under.
+    fn primary_field(&self) -> &str {
+        match self {
+            ItemRule::MustMatch { field_a, .. } => field_a,
+            ItemRule::Compare { field_a, .. } => field_a,
+            ItemRule::RequiredIf { field, .. } => field,
+            ItemRule::RequiredUnless { field, .. } => field,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum FieldCompareOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl FieldCompareOp {
+    fn from_str(op: &str) -> PyResult<Self> {
+        match op {
+            "lt" => Ok(Self::Lt),
+            "lte" => Ok(Self::Le),
+            "gt" => Ok(Self::Gt),
+            "gte" => Ok(Self::Ge),
+            other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                format!("Unknown comparison operator '{}', expected one of: lt, lte, gt, gte", other)
+            )),
+        }
+    }
+
+    fn describe(&self) -> &'static str {
+        match self {
+            Self::Lt => "less than",
+            Self::Le => "less than or equal to",
+            Self::Gt => "greater than",
+            Self::Ge => "greater than or equal to",
+        }
+    }
+
+    fn apply<T: PartialOrd>(&self, a: &T, b: &T) -> bool {
+        match self {
+            Self::Lt => a < b,
+            Self::Le => a <= b,
+            Self::Gt => a > b,
+            Self::Ge => a >= b,
+        }
+    }
+}
+
+/// A value pulled out of a field for a cross-field [`FieldCompareOp`]
+/// comparison. Only numbers and strings (e.g. ISO 8601 date-times, which
+/// sort lexicographically) are supported.
+enum Comparable {
+    Num(f64),
+    Str(String),
+}
+
+fn comparable_value(value: &PyAny) -> PyResult<Comparable> {
+    if value.is_instance_of::<pyo3::types::PyInt>()? || value.is_instance_of::<pyo3::types::PyFloat>()? {
+        Ok(Comparable::Num(value.extract::<f64>()?))
+    } else if value.is_instance_of::<pyo3::types::PyString>()? {
+        Ok(Comparable::Str(value.downcast::<pyo3::types::PyString>()?.to_str()?.to_string()))
+    } else {
+        Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Field comparisons require numeric or string values"
+        ))
+    }
+}
+
+fn compare_values(a: &Comparable, b: &Comparable, op: FieldCompareOp) -> PyResult<bool> {
+    match (a, b) {
+        (Comparable::Num(a), Comparable::Num(b)) => Ok(op.apply(a, b)),
+        (Comparable::Str(a), Comparable::Str(b)) => Ok(op.apply(a, b)),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Cannot compare a numeric field against a string field"
+        )),
+    }
 }
 
 #[derive(Clone)]
@@ -13,6 +123,50 @@ struct FieldValidator {
     field_type: FieldType,
     required: bool,
     constraints: FieldConstraints,
+    custom_validators: Vec<(Py<PyAny>, Option<Py<PyAny>>)>,
+    combinator: Option<ValidatorNode>,
+}
+
+/// A small validator-expression tree so a field can carry a combination of
+/// constraint sets (e.g. "valid email OR valid phone") instead of the
+/// implicit AND over a single flat `FieldConstraints`.
+#[derive(Clone)]
+enum ValidatorNode {
+    Leaf(FieldConstraints),
+    All(Vec<ValidatorNode>),
+    Any(Vec<ValidatorNode>),
+    Not(Box<ValidatorNode>),
+}
+
+/// A single structured validation failure: a dotted/indexed JSON-pointer-
+/// style path (e.g. `address.zipcodes[3]`), a short machine-readable code,
+/// and a human-readable message. Used by `validate_batch_detailed` so a
+/// Python caller isn't limited to a bare pass/fail bool.
+#[derive(Clone)]
+struct ValidationError {
+    path: String,
+    code: String,
+    message: String,
+}
+
+impl ValidationError {
+    fn to_py(&self, py: Python) -> PyResult<Py<pyo3::types::PyDict>> {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("path", &self.path)?;
+        dict.set_item("code", &self.code)?;
+        dict.set_item("message", &self.message)?;
+        Ok(dict.into())
+    }
+}
+
+/// Classify a `validate_value` error message into a short code, since that
+/// function only ever raises a plain `PyValueError` today.
+fn classify_error(message: &str) -> &'static str {
+    if message.starts_with("Expected") || message.starts_with("Invalid") {
+        "type_error"
+    } else {
+        "constraint_error"
+    }
 }
 
 #[derive(Clone, Default)]
@@ -22,8 +176,19 @@ struct FieldConstraints {
     min_value: Option<f64>,
     max_value: Option<f64>,
     pattern: Option<String>,
+    // Compiled once when `pattern` is set rather than on every
+    // `validate_value` call, since `validate_batch` may run it over
+    // millions of rows.
+    compiled_pattern: Option<Regex>,
     email: bool,
     url: bool,
+    ip: bool,
+    ip_v4: bool,
+    ip_v6: bool,
+    credit_card: bool,
+    contains: Option<String>,
+    does_not_contain: Option<String>,
+    non_control_characters: bool,
 }
 
 #[derive(Clone)]
@@ -36,6 +201,8 @@ enum FieldType {
     Dict(Box<FieldType>),
     Custom(String),  // Reference to a custom type name
     Any,
+    Uuid,
+    DateTime,
 }
 
 #[pymethods]
@@ -46,6 +213,7 @@ impl StreamValidatorCore {
             schema: HashMap::new(),
             batch_size: 1000,
             custom_types: HashMap::new(),
+            item_rules: Vec::new(),
         }
     }
 
@@ -71,17 +239,25 @@ impl StreamValidatorCore {
             PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Custom type {} not defined", type_name))
         })?;
 
-        custom_type.insert(field_name, FieldValidator { 
-            field_type: parsed_field_type, 
+        custom_type.insert(field_name, FieldValidator {
+            field_type: parsed_field_type,
             required,
             constraints: FieldConstraints::default(),
+            custom_validators: Vec::new(),
+            combinator: None,
         });
         Ok(())
     }
 
     fn add_field(&mut self, name: String, field_type: &str, required: bool) -> PyResult<()> {
         let field_type = self.parse_field_type(field_type)?;
-        self.schema.insert(name, FieldValidator { field_type, required, constraints: FieldConstraints::default() });
+        self.schema.insert(name, FieldValidator {
+            field_type,
+            required,
+            constraints: FieldConstraints::default(),
+            custom_validators: Vec::new(),
+            combinator: None,
+        });
         Ok(())
     }
 
@@ -89,10 +265,175 @@ impl StreamValidatorCore {
         self.batch_size = size;
     }
 
+    /// Attach string/number constraints to a previously-added root field.
+    /// `pattern` is compiled to a `Regex` immediately so `validate_batch`
+    /// never recompiles it per item.
+    #[pyo3(signature = (field_name, min_length=None, max_length=None, min_value=None, max_value=None, pattern=None, email=None, url=None, ip=None, ip_v4=None, ip_v6=None, credit_card=None, contains=None, does_not_contain=None, non_control_characters=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn set_field_constraints(
+        &mut self,
+        field_name: String,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+        min_value: Option<f64>,
+        max_value: Option<f64>,
+        pattern: Option<String>,
+        email: Option<bool>,
+        url: Option<bool>,
+        ip: Option<bool>,
+        ip_v4: Option<bool>,
+        ip_v6: Option<bool>,
+        credit_card: Option<bool>,
+        contains: Option<String>,
+        does_not_contain: Option<String>,
+        non_control_characters: Option<bool>,
+    ) -> PyResult<()> {
+        let validator = self.schema.get_mut(&field_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Field {} not defined", field_name))
+        })?;
+        apply_constraints(
+            &mut validator.constraints,
+            min_length, max_length, min_value, max_value, pattern, email, url,
+            ip, ip_v4, ip_v6, credit_card, contains, does_not_contain, non_control_characters,
+        )
+    }
+
+    /// Same as [`set_field_constraints`] but for a field of a previously
+    /// defined custom type.
+    #[pyo3(signature = (type_name, field_name, min_length=None, max_length=None, min_value=None, max_value=None, pattern=None, email=None, url=None, ip=None, ip_v4=None, ip_v6=None, credit_card=None, contains=None, does_not_contain=None, non_control_characters=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn set_custom_type_field_constraints(
+        &mut self,
+        type_name: String,
+        field_name: String,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+        min_value: Option<f64>,
+        max_value: Option<f64>,
+        pattern: Option<String>,
+        email: Option<bool>,
+        url: Option<bool>,
+        ip: Option<bool>,
+        ip_v4: Option<bool>,
+        ip_v6: Option<bool>,
+        credit_card: Option<bool>,
+        contains: Option<String>,
+        does_not_contain: Option<String>,
+        non_control_characters: Option<bool>,
+    ) -> PyResult<()> {
+        let custom_type = self.custom_types.get_mut(&type_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Custom type {} not defined", type_name))
+        })?;
+        let validator = custom_type.get_mut(&field_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Field {} not defined on {}", field_name, type_name))
+        })?;
+        apply_constraints(
+            &mut validator.constraints,
+            min_length, max_length, min_value, max_value, pattern, email, url,
+            ip, ip_v4, ip_v6, credit_card, contains, does_not_contain, non_control_characters,
+        )
+    }
+
     fn get_batch_size(&self) -> usize {
         self.batch_size
     }
 
+    /// Attach a composite validator-expression tree to a root field, e.g.
+    /// `{"any": [{"email": True}, {"pattern": r"^\+?\d{7,15}$"}]}` for
+    /// "valid email OR valid phone". Each leaf accepts the same keys as
+    /// [`set_field_constraints`]; branches nest under `"all"`/`"any"`/`"not"`.
+    fn set_field_validator(&mut self, field_name: String, node: &PyAny) -> PyResult<()> {
+        let parsed = parse_validator_node(node)?;
+        let validator = self.schema.get_mut(&field_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Field {} not defined", field_name))
+        })?;
+        validator.combinator = Some(parsed);
+        Ok(())
+    }
+
+    /// Same as [`set_field_validator`] but for a field of a previously
+    /// defined custom type.
+    fn set_custom_type_field_validator(&mut self, type_name: String, field_name: String, node: &PyAny) -> PyResult<()> {
+        let parsed = parse_validator_node(node)?;
+        let custom_type = self.custom_types.get_mut(&type_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Custom type {} not defined", type_name))
+        })?;
+        let validator = custom_type.get_mut(&field_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Field {} not defined on {}", field_name, type_name))
+        })?;
+        validator.combinator = Some(parsed);
+        Ok(())
+    }
+
+    /// Register a Python callable as an extra check on a root field, run
+    /// after its built-in constraints pass. `callable` is invoked with the
+    /// field value (and `context`, if given); a string return or a raised
+    /// exception becomes the validation error, `None`/truthy means valid.
+    #[pyo3(signature = (field_name, callable, context=None))]
+    fn add_custom_validator(&mut self, field_name: String, callable: Py<PyAny>, context: Option<Py<PyAny>>) -> PyResult<()> {
+        let validator = self.schema.get_mut(&field_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Field {} not defined", field_name))
+        })?;
+        validator.custom_validators.push((callable, context));
+        Ok(())
+    }
+
+    /// Same as [`add_custom_validator`] but for a field of a previously
+    /// defined custom type.
+    #[pyo3(signature = (type_name, field_name, callable, context=None))]
+    fn add_custom_type_field_validator(
+        &mut self,
+        type_name: String,
+        field_name: String,
+        callable: Py<PyAny>,
+        context: Option<Py<PyAny>>,
+    ) -> PyResult<()> {
+        let custom_type = self.custom_types.get_mut(&type_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Custom type {} not defined", type_name))
+        })?;
+        let validator = custom_type.get_mut(&field_name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Field {} not defined on {}", field_name, type_name))
+        })?;
+        validator.custom_validators.push((callable, context));
+        Ok(())
+    }
+
+    /// Require `field_a` and `field_b` to hold equal values, e.g. a
+    /// `password`/`password_confirm` pair. A missing field on either side is
+    /// left to `required`/`add_required_if` to flag; this rule only fires
+    /// once both are present.
+    fn add_must_match(&mut self, field_a: String, field_b: String) -> PyResult<()> {
+        self.item_rules.push(ItemRule::MustMatch { field_a, field_b });
+        Ok(())
+    }
+
+    /// Require `field_a` to stand in `op` relation to `field_b`, e.g.
+    /// `add_field_comparison("end_date", "start_date", "gte")` for "end_date
+    /// must be on or after start_date". `op` is one of `lt`, `lte`, `gt`,
+    /// `gte`. Both fields must be numbers, or both ISO 8601 date-time
+    /// strings (which sort lexicographically).
+    fn add_field_comparison(&mut self, field_a: String, field_b: String, op: &str) -> PyResult<()> {
+        let op = FieldCompareOp::from_str(op)?;
+        self.item_rules.push(ItemRule::Compare { field_a, field_b, op });
+        Ok(())
+    }
+
+    /// Make `field_name` required when `depends_on` is present in the item
+    /// (or, if `equals` is given, when `depends_on` equals that value).
+    #[pyo3(signature = (field_name, depends_on, equals=None))]
+    fn add_required_if(&mut self, field_name: String, depends_on: String, equals: Option<Py<PyAny>>) -> PyResult<()> {
+        self.item_rules.push(ItemRule::RequiredIf { field: field_name, depends_on, equals });
+        Ok(())
+    }
+
+    /// Make `field_name` required unless `depends_on` is present in the item
+    /// (or, if `equals` is given, unless `depends_on` equals that value).
+    #[pyo3(signature = (field_name, depends_on, equals=None))]
+    fn add_required_unless(&mut self, field_name: String, depends_on: String, equals: Option<Py<PyAny>>) -> PyResult<()> {
+        self.item_rules.push(ItemRule::RequiredUnless { field: field_name, depends_on, equals });
+        Ok(())
+    }
+
     fn validate_batch(&self, items: Vec<&PyAny>) -> PyResult<Vec<bool>> {
         let mut results = Vec::with_capacity(items.len());
         
@@ -105,16 +446,31 @@ impl StreamValidatorCore {
         Ok(results)
     }
 
+    /// Batch form of validation that, unlike [`validate_batch`], never stops
+    /// at the first failing field: each item gets a (possibly empty) list
+    /// of `{path, code, message}` dicts covering every failure found in it.
+    fn validate_batch_detailed(&self, py: Python, items: Vec<&PyAny>) -> PyResult<Vec<Vec<Py<pyo3::types::PyDict>>>> {
+        let mut results = Vec::with_capacity(items.len());
+        for item in items {
+            let errors = self.validate_item_detailed(item)?;
+            let py_errors: PyResult<Vec<Py<pyo3::types::PyDict>>> =
+                errors.iter().map(|e| e.to_py(py)).collect();
+            results.push(py_errors?);
+        }
+        Ok(results)
+    }
+
     fn validate_item_internal(&self, item: &PyAny) -> PyResult<bool> {
         if !item.is_instance_of::<pyo3::types::PyDict>()? {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Item must be a dict"));
         }
 
         let dict = item.downcast::<pyo3::types::PyDict>()?;
-        
+
         for (field_name, validator) in &self.schema {
             if let Some(value) = dict.get_item(field_name) {
-                self.validate_value(value, &validator.field_type, &validator.constraints)?;
+                self.validate_field_value(value, validator)?;
+                self.run_custom_validators(value, validator)?;
             } else if validator.required {
                 return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                     format!("Required field {} is missing", field_name)
@@ -122,6 +478,8 @@ impl StreamValidatorCore {
             }
         }
 
+        self.check_item_rules(dict)?;
+
         Ok(true)
     }
 }
@@ -131,7 +489,9 @@ impl StreamValidatorCore {
     fn parse_field_type(&self, field_type: &str) -> PyResult<FieldType> {
         // First check for primitive types
         match field_type {
-            "str" | "string" | "email" | "url" | "uuid" | "date-time" => return Ok(FieldType::String),
+            "str" | "string" | "email" | "url" => return Ok(FieldType::String),
+            "uuid" => return Ok(FieldType::Uuid),
+            "date-time" => return Ok(FieldType::DateTime),
             "int" | "integer" => return Ok(FieldType::Integer),
             "float" | "number" => return Ok(FieldType::Float),
             "bool" | "boolean" => return Ok(FieldType::Boolean),
@@ -153,6 +513,327 @@ impl StreamValidatorCore {
         Ok(FieldType::Custom(field_type.to_string()))
     }
 
+    /// Path-accumulating counterpart to [`validate_item_internal`]: collects
+    /// every failing field instead of returning on the first one.
+    fn validate_item_detailed(&self, item: &PyAny) -> PyResult<Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        if !item.is_instance_of::<pyo3::types::PyDict>()? {
+            errors.push(ValidationError {
+                path: String::new(),
+                code: "type_error".to_string(),
+                message: "Item must be a dict".to_string(),
+            });
+            return Ok(errors);
+        }
+
+        let dict = item.downcast::<pyo3::types::PyDict>()?;
+        for (field_name, validator) in &self.schema {
+            if let Some(value) = dict.get_item(field_name) {
+                let errors_before = errors.len();
+                self.validate_field_value_detailed(value, validator, field_name, &mut errors)?;
+                if errors.len() == errors_before {
+                    self.push_custom_validator_errors(value, validator, field_name, &mut errors)?;
+                }
+            } else if validator.required {
+                errors.push(ValidationError {
+                    path: field_name.clone(),
+                    code: "missing_field".to_string(),
+                    message: format!("Required field {} is missing", field_name),
+                });
+            }
+        }
+
+        self.check_item_rules_detailed(dict, &mut errors)?;
+
+        Ok(errors)
+    }
+
+    /// Evaluate every registered [`ItemRule`] against `dict`, after each
+    /// field's own validation has already run.
+    fn check_item_rules(&self, dict: &pyo3::types::PyDict) -> PyResult<()> {
+        for rule in &self.item_rules {
+            self.check_item_rule(dict, rule)?;
+        }
+        Ok(())
+    }
+
+    /// Path-accumulating counterpart to [`check_item_rules`]: a failing rule
+    /// contributes one error at [`ItemRule::primary_field`] rather than
+    /// stopping the whole item.
+    fn check_item_rules_detailed(&self, dict: &pyo3::types::PyDict, errors: &mut Vec<ValidationError>) -> PyResult<()> {
+        for rule in &self.item_rules {
+            if let Err(e) = self.check_item_rule(dict, rule) {
+                let message = e.value(dict.py()).str()?.to_string();
+                errors.push(ValidationError {
+                    path: rule.primary_field().to_string(),
+                    code: "item_rule_error".to_string(),
+                    message,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate a single [`ItemRule`] against `dict`, returning a
+    /// `PyValueError` describing the violation if it fails.
+    fn check_item_rule(&self, dict: &pyo3::types::PyDict, rule: &ItemRule) -> PyResult<()> {
+        match rule {
+            ItemRule::MustMatch { field_a, field_b } => {
+                if let (Some(a), Some(b)) = (dict.get_item(field_a), dict.get_item(field_b)) {
+                    if !a.eq(b)? {
+                        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                            format!("Field {} must match field {}", field_a, field_b)
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            ItemRule::Compare { field_a, field_b, op } => {
+                if let (Some(a), Some(b)) = (dict.get_item(field_a), dict.get_item(field_b)) {
+                    let a = comparable_value(a)?;
+                    let b = comparable_value(b)?;
+                    if !compare_values(&a, &b, *op)? {
+                        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                            format!("Field {} must be {} field {}", field_a, op.describe(), field_b)
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            ItemRule::RequiredIf { field, depends_on, equals } => {
+                if self.item_rule_condition_matches(dict, depends_on, equals.as_ref())? && dict.get_item(field).is_none() {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        format!("Field {} is required because {} is present", field, depends_on)
+                    ));
+                }
+                Ok(())
+            }
+            ItemRule::RequiredUnless { field, depends_on, equals } => {
+                if !self.item_rule_condition_matches(dict, depends_on, equals.as_ref())? && dict.get_item(field).is_none() {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        format!("Field {} is required unless {} is present", field, depends_on)
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Whether `depends_on` is present in `dict` and, if `equals` is given,
+    /// equal to it. Backs both `RequiredIf` and `RequiredUnless`.
+    fn item_rule_condition_matches(&self, dict: &pyo3::types::PyDict, depends_on: &str, equals: Option<&Py<PyAny>>) -> PyResult<bool> {
+        match dict.get_item(depends_on) {
+            None => Ok(false),
+            Some(value) => match equals {
+                None => Ok(true),
+                Some(expected) => value.eq(expected.as_ref(value.py())),
+            },
+        }
+    }
+
+    /// Path-accumulating counterpart to [`validate_value`]. Continues
+    /// validating siblings after a failure and prefixes nested errors with
+    /// `path` so e.g. a bad list element at index 3 reports `address.zipcodes[3]`.
+    fn validate_value_detailed(
+        &self,
+        value: &PyAny,
+        field_type: &FieldType,
+        constraints: &FieldConstraints,
+        path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) -> PyResult<()> {
+        match field_type {
+            FieldType::List(inner_type) => {
+                if !value.is_instance_of::<pyo3::types::PyList>()? {
+                    errors.push(ValidationError {
+                        path: path.to_string(),
+                        code: "type_error".to_string(),
+                        message: "Expected list".to_string(),
+                    });
+                    return Ok(());
+                }
+                for (i, item) in value.downcast::<pyo3::types::PyList>()?.iter().enumerate() {
+                    let item_path = format!("{}[{}]", path, i);
+                    self.validate_value_detailed(item, inner_type, constraints, &item_path, errors)?;
+                }
+                Ok(())
+            }
+            FieldType::Dict(inner_type) => {
+                if !value.is_instance_of::<pyo3::types::PyDict>()? {
+                    errors.push(ValidationError {
+                        path: path.to_string(),
+                        code: "type_error".to_string(),
+                        message: "Expected dict".to_string(),
+                    });
+                    return Ok(());
+                }
+                for (key, item) in value.downcast::<pyo3::types::PyDict>()?.iter() {
+                    let key_str = key.str()?.to_str()?.to_string();
+                    let item_path = format!("{}.{}", path, key_str);
+                    self.validate_value_detailed(item, inner_type, constraints, &item_path, errors)?;
+                }
+                Ok(())
+            }
+            FieldType::Custom(type_name) => {
+                if !value.is_instance_of::<pyo3::types::PyDict>()? {
+                    errors.push(ValidationError {
+                        path: path.to_string(),
+                        code: "type_error".to_string(),
+                        message: format!("Custom type {} must be a dict", type_name),
+                    });
+                    return Ok(());
+                }
+                let dict = value.downcast::<pyo3::types::PyDict>()?;
+                let custom_type = self.custom_types.get(type_name).ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Custom type {} not found", type_name))
+                })?;
+                for (field_name, validator) in custom_type {
+                    let field_path = format!("{}.{}", path, field_name);
+                    if let Some(field_value) = dict.get_item(field_name) {
+                        let errors_before = errors.len();
+                        self.validate_field_value_detailed(field_value, validator, &field_path, errors)?;
+                        if errors.len() == errors_before {
+                            self.push_custom_validator_errors(field_value, validator, &field_path, errors)?;
+                        }
+                    } else if validator.required {
+                        errors.push(ValidationError {
+                            path: field_path,
+                            code: "missing_field".to_string(),
+                            message: format!("Required field {} is missing in custom type {}", field_name, type_name),
+                        });
+                    }
+                }
+                Ok(())
+            }
+            _ => {
+                if let Err(e) = self.validate_value(value, field_type, constraints) {
+                    let message = e.value(value.py()).str()?.to_string();
+                    errors.push(ValidationError {
+                        path: path.to_string(),
+                        code: classify_error(&message).to_string(),
+                        message,
+                    });
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Run a field's registered custom validators after its built-in
+    /// constraints pass. `None`/truthy means valid; a returned string becomes
+    /// a `PyValueError`, and an exception raised by the callable is returned
+    /// as the error unchanged.
+    fn run_custom_validators(&self, value: &PyAny, validator: &FieldValidator) -> PyResult<()> {
+        let py = value.py();
+        for (callable, context) in &validator.custom_validators {
+            let result = match context {
+                Some(ctx) => callable.call1(py, (value, ctx.clone_ref(py)))?,
+                None => callable.call1(py, (value,))?,
+            };
+            let result = result.into_ref(py);
+            if let Ok(message) = result.downcast::<pyo3::types::PyString>() {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(message.to_str()?.to_string()));
+            }
+            if result.is_none() {
+                continue;
+            }
+            if !result.is_true()? {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Custom validator rejected value"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Detailed-path counterpart to [`run_custom_validators`]: pushes a
+    /// structured error instead of returning early.
+    fn push_custom_validator_errors(
+        &self,
+        value: &PyAny,
+        validator: &FieldValidator,
+        path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) -> PyResult<()> {
+        if let Err(e) = self.run_custom_validators(value, validator) {
+            let message = e.value(value.py()).str()?.to_string();
+            errors.push(ValidationError {
+                path: path.to_string(),
+                code: "custom_validator_error".to_string(),
+                message,
+            });
+        }
+        Ok(())
+    }
+
+    /// Entry point for validating a single field's value: dispatches to the
+    /// field's [`ValidatorNode`] tree when one is attached via
+    /// `set_field_validator`/`set_custom_type_field_validator`, otherwise
+    /// falls back to the plain AND-of-constraints path used everywhere else.
+    fn validate_field_value(&self, value: &PyAny, validator: &FieldValidator) -> PyResult<()> {
+        match &validator.combinator {
+            Some(node) => self.validate_node(value, &validator.field_type, node),
+            None => self.validate_value(value, &validator.field_type, &validator.constraints),
+        }
+    }
+
+    /// Detailed-path counterpart to [`validate_field_value`]: a failing
+    /// combinator tree contributes a single structured error at `path`
+    /// rather than one per branch, since `Any`/`Not` failures don't map to
+    /// one specific constraint.
+    fn validate_field_value_detailed(
+        &self,
+        value: &PyAny,
+        validator: &FieldValidator,
+        path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) -> PyResult<()> {
+        match &validator.combinator {
+            Some(node) => {
+                if let Err(e) = self.validate_node(value, &validator.field_type, node) {
+                    let message = e.value(value.py()).str()?.to_string();
+                    errors.push(ValidationError {
+                        path: path.to_string(),
+                        code: "validator_error".to_string(),
+                        message,
+                    });
+                }
+                Ok(())
+            }
+            None => self.validate_value_detailed(value, &validator.field_type, &validator.constraints, path, errors),
+        }
+    }
+
+    /// Evaluate a [`ValidatorNode`] expression tree against `value`. `All`
+    /// requires every branch to pass; `Any` passes as soon as one branch
+    /// does and otherwise reports that none matched; `Not` inverts a branch.
+    fn validate_node(&self, value: &PyAny, field_type: &FieldType, node: &ValidatorNode) -> PyResult<()> {
+        match node {
+            ValidatorNode::Leaf(constraints) => self.validate_value(value, field_type, constraints),
+            ValidatorNode::All(nodes) => {
+                for n in nodes {
+                    self.validate_node(value, field_type, n)?;
+                }
+                Ok(())
+            }
+            ValidatorNode::Any(nodes) => {
+                for n in nodes {
+                    if self.validate_node(value, field_type, n).is_ok() {
+                        return Ok(());
+                    }
+                }
+                Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "Value did not satisfy any of the alternative validators",
+                ))
+            }
+            ValidatorNode::Not(inner) => match self.validate_node(value, field_type, inner) {
+                Ok(()) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "Value matched a validator it must not match",
+                )),
+                Err(_) => Ok(()),
+            },
+        }
+    }
+
     fn validate_value(&self, value: &PyAny, field_type: &FieldType, constraints: &FieldConstraints) -> PyResult<()> {
         match field_type {
             FieldType::String => {
@@ -191,14 +872,62 @@ impl StreamValidatorCore {
                     }
                 }
 
-                // Regex pattern validation
-                if let Some(pattern) = &constraints.pattern {
-                    if !regex_match(s, pattern) {
+                // Regex pattern validation (compiled once in `set_field_constraints`)
+                if let Some(regex) = &constraints.compiled_pattern {
+                    if !regex.is_match(s) {
+                        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                            format!("String does not match pattern: {}", constraints.pattern.as_deref().unwrap_or(""))
+                        ));
+                    }
+                }
+
+                if constraints.ip || constraints.ip_v4 || constraints.ip_v6 {
+                    validate_ip_constraint(s, constraints)?;
+                }
+
+                if constraints.credit_card && !is_valid_credit_card(s) {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid credit card number"));
+                }
+
+                if let Some(needle) = &constraints.contains {
+                    if !s.contains(needle.as_str()) {
                         return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                            format!("String does not match pattern: {}", pattern)
+                            format!("String must contain '{}'", needle)
                         ));
                     }
                 }
+
+                if let Some(needle) = &constraints.does_not_contain {
+                    if s.contains(needle.as_str()) {
+                        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                            format!("String must not contain '{}'", needle)
+                        ));
+                    }
+                }
+
+                if constraints.non_control_characters && s.chars().any(|c| c.is_control()) {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("String must not contain control characters"));
+                }
+            }
+            FieldType::Uuid => {
+                if !value.is_instance_of::<pyo3::types::PyString>()? {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Expected string"));
+                }
+                let s = value.downcast::<pyo3::types::PyString>()?.to_str()?;
+                if !is_valid_uuid(s) {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid UUID: {}", s)));
+                }
+            }
+            FieldType::DateTime => {
+                if !value.is_instance_of::<pyo3::types::PyString>()? {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Expected string"));
+                }
+                let s = value.downcast::<pyo3::types::PyString>()?.to_str()?;
+                if !is_valid_datetime(s) {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        format!("Invalid RFC 3339 date-time: {}", s)
+                    ));
+                }
             }
             FieldType::Integer | FieldType::Float => {
                 let num = if value.is_instance_of::<pyo3::types::PyInt>()? {
@@ -257,7 +986,8 @@ impl StreamValidatorCore {
                 
                 for (field_name, validator) in custom_type {
                     if let Some(field_value) = dict.get_item(field_name) {
-                        self.validate_value(field_value, &validator.field_type, &validator.constraints)?;
+                        self.validate_field_value(field_value, validator)?;
+                        self.run_custom_validators(field_value, validator)?;
                     } else if validator.required {
                         return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                             format!("Required field {} is missing in custom type {}", field_name, type_name)
@@ -292,10 +1022,201 @@ fn validate_url(s: &str) -> bool {
     s.starts_with("http://") || s.starts_with("https://")
 }
 
-fn regex_match(s: &str, pattern: &str) -> bool {
-    // Basic pattern matching (can be enhanced with proper regex)
-    // For now, just check if pattern exists in string
-    s.contains(pattern)
+/// Merge the given constraint overrides into `constraints` in place,
+/// compiling `pattern` to a `Regex` up front and returning a descriptive
+/// error if it fails to compile.
+#[allow(clippy::too_many_arguments)]
+fn apply_constraints(
+    constraints: &mut FieldConstraints,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    min_value: Option<f64>,
+    max_value: Option<f64>,
+    pattern: Option<String>,
+    email: Option<bool>,
+    url: Option<bool>,
+    ip: Option<bool>,
+    ip_v4: Option<bool>,
+    ip_v6: Option<bool>,
+    credit_card: Option<bool>,
+    contains: Option<String>,
+    does_not_contain: Option<String>,
+    non_control_characters: Option<bool>,
+) -> PyResult<()> {
+    if let Some(min_length) = min_length {
+        constraints.min_length = Some(min_length);
+    }
+    if let Some(max_length) = max_length {
+        constraints.max_length = Some(max_length);
+    }
+    if let Some(min_value) = min_value {
+        constraints.min_value = Some(min_value);
+    }
+    if let Some(max_value) = max_value {
+        constraints.max_value = Some(max_value);
+    }
+    if let Some(email) = email {
+        constraints.email = email;
+    }
+    if let Some(url) = url {
+        constraints.url = url;
+    }
+    if let Some(ip) = ip {
+        constraints.ip = ip;
+    }
+    if let Some(ip_v4) = ip_v4 {
+        constraints.ip_v4 = ip_v4;
+    }
+    if let Some(ip_v6) = ip_v6 {
+        constraints.ip_v6 = ip_v6;
+    }
+    if let Some(credit_card) = credit_card {
+        constraints.credit_card = credit_card;
+    }
+    if let Some(contains) = contains {
+        constraints.contains = Some(contains);
+    }
+    if let Some(does_not_contain) = does_not_contain {
+        constraints.does_not_contain = Some(does_not_contain);
+    }
+    if let Some(non_control_characters) = non_control_characters {
+        constraints.non_control_characters = non_control_characters;
+    }
+    if let Some(pattern) = pattern {
+        // Anchored so the whole string must match, not just a substring of
+        // it — e.g. pattern "foo" rejects "xfoox" instead of accepting it.
+        let anchored = format!("^(?:{})$", pattern);
+        let compiled = Regex::new(&anchored).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid regex pattern '{}': {}", pattern, e))
+        })?;
+        constraints.compiled_pattern = Some(compiled);
+        constraints.pattern = Some(pattern);
+    }
+    Ok(())
+}
+
+/// Parse a Python validator-expression node into a [`ValidatorNode`]. A node
+/// is a dict: `{"all": [...]}` / `{"any": [...]}` nest branches, `{"not": {...}}`
+/// wraps a single branch, and anything else is treated as a leaf of the same
+/// keys [`set_field_constraints`] accepts (`email`, `pattern`, `min_length`, ...).
+fn parse_validator_node(node: &PyAny) -> PyResult<ValidatorNode> {
+    let dict = node.downcast::<pyo3::types::PyDict>().map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>("Validator node must be a dict")
+    })?;
+
+    if let Some(items) = dict.get_item("all") {
+        return Ok(ValidatorNode::All(parse_validator_list(items)?));
+    }
+    if let Some(items) = dict.get_item("any") {
+        return Ok(ValidatorNode::Any(parse_validator_list(items)?));
+    }
+    if let Some(inner) = dict.get_item("not") {
+        return Ok(ValidatorNode::Not(Box::new(parse_validator_node(inner)?)));
+    }
+
+    let mut constraints = FieldConstraints::default();
+    apply_constraints(
+        &mut constraints,
+        extract_opt(dict, "min_length")?,
+        extract_opt(dict, "max_length")?,
+        extract_opt(dict, "min_value")?,
+        extract_opt(dict, "max_value")?,
+        extract_opt(dict, "pattern")?,
+        extract_opt(dict, "email")?,
+        extract_opt(dict, "url")?,
+        extract_opt(dict, "ip")?,
+        extract_opt(dict, "ip_v4")?,
+        extract_opt(dict, "ip_v6")?,
+        extract_opt(dict, "credit_card")?,
+        extract_opt(dict, "contains")?,
+        extract_opt(dict, "does_not_contain")?,
+        extract_opt(dict, "non_control_characters")?,
+    )?;
+    Ok(ValidatorNode::Leaf(constraints))
+}
+
+fn parse_validator_list(items: &PyAny) -> PyResult<Vec<ValidatorNode>> {
+    let list = items.downcast::<pyo3::types::PyList>().map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>("Expected a list of validator nodes")
+    })?;
+    list.iter().map(parse_validator_node).collect()
+}
+
+/// Fetch and extract an optional key from a validator-node dict, returning
+/// `None` when the key is absent rather than erroring.
+fn extract_opt<'a, T: pyo3::FromPyObject<'a>>(dict: &'a pyo3::types::PyDict, key: &str) -> PyResult<Option<T>> {
+    match dict.get_item(key) {
+        Some(v) => Ok(Some(v.extract()?)),
+        None => Ok(None),
+    }
+}
+
+/// RFC 3339 date-time format, e.g. `2024-01-01T12:00:00Z` or
+/// `2024-01-01T12:00:00.123+02:00`. Compiled once and reused.
+fn datetime_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:\d{2})$").unwrap()
+    })
+}
+
+fn is_valid_datetime(s: &str) -> bool {
+    datetime_regex().is_match(s)
+}
+
+/// UUID in canonical 8-4-4-4-12 hyphenated hex form.
+fn is_valid_uuid(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    if bytes.len() != 36 {
+        return false;
+    }
+    bytes.iter().enumerate().all(|(i, b)| match i {
+        8 | 13 | 18 | 23 => *b == b'-',
+        _ => b.is_ascii_hexdigit(),
+    })
+}
+
+/// Luhn checksum over a credit card number, ignoring spaces and dashes.
+fn is_valid_credit_card(s: &str) -> bool {
+    let digits: String = s.chars().filter(|c| *c != ' ' && *c != '-').collect();
+    if digits.is_empty() || digits.len() < 13 || digits.len() > 19 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| {
+            let mut digit = c.to_digit(10).unwrap();
+            if i % 2 == 1 {
+                digit *= 2;
+                if digit > 9 {
+                    digit -= 9;
+                }
+            }
+            digit
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+fn validate_ip_constraint(s: &str, constraints: &FieldConstraints) -> PyResult<()> {
+    let parsed = IpAddr::from_str(s).map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid IP address: {}", s))
+    })?;
+    if constraints.ip_v4 && !parsed.is_ipv4() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            format!("Expected an IPv4 address, got: {}", s)
+        ));
+    }
+    if constraints.ip_v6 && !parsed.is_ipv6() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            format!("Expected an IPv6 address, got: {}", s)
+        ));
+    }
+    Ok(())
 }
 
 #[pymodule]